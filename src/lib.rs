@@ -1,7 +1,7 @@
 #![no_std]
 
 ///
-/// 
+///
 /// A simple ring buffer implementation in Rust.
 /// This ring buffer supports fixed-size storage and provides methods for pushing, popping, and reading data.
 /// It is designed to be efficient and easy to use, with a focus on performance and safety.
@@ -14,7 +14,7 @@
 /// let item = rb.pop();
 /// assert_eq!(item, Some(42));
 /// ```
-/// 
+///
 
 #[derive(Debug)]
 pub enum RingBufferError {
@@ -23,20 +23,60 @@ pub enum RingBufferError {
     BufferIncomplete,
 }
 
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// A fixed-size ring buffer over `S` slots of `T`.
+///
+/// `head` and `tail` are free-running counters that advance as items are
+/// written and read (not wrapped into `0..S`, and reset to `MID` by
+/// [`RingBuffer::clear`]); the physical array index is
+/// always `head % S` / `tail % S`. This means the live length is simply
+/// `head.wrapping_sub(tail)`, with no separate counter to keep in sync and no
+/// ambiguity between an empty and a full buffer. They are stored as
+/// `AtomicUsize` behind an `UnsafeCell`-backed array so the same layout can be
+/// shared, without a lock, between the [`Producer`]/[`Consumer`] handles
+/// returned by [`RingBuffer::split`]; the sequential methods below (`push`,
+/// `pop`, ...) take `&mut self` and only ever use `Ordering::Relaxed`, since
+/// exclusive access is already guaranteed by the borrow checker in that mode.
+///
+/// [`RingBuffer::push_front`] and [`RingBuffer::pop_back`] walk `tail`/`head`
+/// backwards, which is why both start out at `MID` rather than `0`: it gives
+/// either index room to move below its starting point without underflowing,
+/// while `wrapping_sub` keeps the length calculation correct even if one ever
+/// does wrap past `0`.
 pub struct RingBuffer<T, const S: usize>
 where
     T: Default + Copy,
 {
-    buffer: [T; S],
-    head: usize,
-    tail: usize,
-    len: usize,
+    buffer: UnsafeCell<[T; S]>,
+    head: AtomicUsize,
+    tail: AtomicUsize,
 }
 
+// SAFETY: `buffer` is only ever indexed at `head % S` (by the producer side)
+// or `tail % S` (by the consumer side), and the split handles hand out
+// disjoint, non-overlapping access by construction: the producer never
+// writes past an index the consumer hasn't yet read, and the consumer never
+// reads past an index the producer hasn't yet published (enforced by the
+// Acquire/Release pairing on `head`/`tail`). This makes it sound to share a
+// `&RingBuffer<T, S>` across the producer and consumer threads. Crucially,
+// [`RingBuffer::split`] takes `&'static mut self`, so the borrow checker
+// guarantees those are the *only* two handles left standing: the original
+// binding is consumed for good, so no third, unsynchronized `&self` accessor
+// can ever observe `buffer` while the split handles are live.
+unsafe impl<T, const S: usize> Sync for RingBuffer<T, S> where T: Default + Copy + Send {}
+
 impl<T, const S: usize> RingBuffer<T, S>
 where
     T: Default + Copy,
 {
+    /// Starting value for `head`/`tail`: far from `0` and `usize::MAX` so that
+    /// [`RingBuffer::push_front`]/[`RingBuffer::pop_back`] can move an index
+    /// backwards without underflowing in ordinary use, and a multiple of `S`
+    /// so a fresh buffer still starts filling at physical index `0`.
+    const MID: usize = (usize::MAX / 2 / S) * S;
+
     /**
      * Creates a new instance of `RingBuffer` with a fixed size.
      *  ```
@@ -46,13 +86,25 @@ where
      */
     pub fn new() -> Self {
         RingBuffer {
-            buffer: [T::default(); S],
-            head: 0,
-            tail: 0,
-            len: 0,
+            buffer: UnsafeCell::new([T::default(); S]),
+            head: AtomicUsize::new(Self::MID),
+            tail: AtomicUsize::new(Self::MID),
         }
     }
 
+    #[inline]
+    fn buf(&self) -> &[T; S] {
+        // SAFETY: called only through `&self`/`&mut self` sequential methods,
+        // which never overlap with a live `Producer`/`Consumer` borrow.
+        unsafe { &*self.buffer.get() }
+    }
+
+    #[inline]
+    fn buf_mut(&mut self) -> &mut [T; S] {
+        // SAFETY: `&mut self` guarantees exclusive access.
+        unsafe { &mut *self.buffer.get() }
+    }
+
     /**
      * Pushes an item into the ring buffer.
      * Returns an error if the buffer is full.
@@ -63,15 +115,49 @@ where
      * ```
      */
     pub fn push(&mut self, item: T) -> Result<(), RingBufferError> {
-        if self.len == S {
+        let head = self.head.load(Ordering::Relaxed);
+        let tail = self.tail.load(Ordering::Relaxed);
+        if head.wrapping_sub(tail) == S {
             return Err(RingBufferError::Full);
         }
-        self.buffer[self.head] = item;
-        self.head = (self.head + 1) % S;
-        self.len += 1;
+        let idx = head % S;
+        self.buf_mut()[idx] = item;
+        self.head.store(head + 1, Ordering::Relaxed);
         Ok(())
     }
 
+    /**
+     * Pushes an item into the ring buffer, never failing. When the buffer is
+     * full, the oldest element is evicted by advancing `tail` along with
+     * `head`, and the evicted value is returned; otherwise returns `None`.
+     *
+     * This is the behavior wanted by fixed-horizon telemetry or log buffers
+     * that only need to retain the most recent `S` samples.
+     * ```
+     * use ring_buffer_no_std::RingBuffer;
+     * let mut rb: RingBuffer<u32, 2> = RingBuffer::new();
+     * assert_eq!(rb.push_overwrite(1), None);
+     * assert_eq!(rb.push_overwrite(2), None);
+     * assert_eq!(rb.push_overwrite(3), Some(1));
+     * ```
+     */
+    pub fn push_overwrite(&mut self, item: T) -> Option<T> {
+        let head = self.head.load(Ordering::Relaxed);
+        let tail = self.tail.load(Ordering::Relaxed);
+        let idx = head % S;
+        if head.wrapping_sub(tail) == S {
+            let evicted = self.buf()[idx];
+            self.buf_mut()[idx] = item;
+            self.head.store(head + 1, Ordering::Relaxed);
+            self.tail.store(tail + 1, Ordering::Relaxed);
+            Some(evicted)
+        } else {
+            self.buf_mut()[idx] = item;
+            self.head.store(head + 1, Ordering::Relaxed);
+            None
+        }
+    }
+
     /**
      * Pops an item from the ring buffer.
      * Returns `None` if the buffer is empty.
@@ -84,12 +170,73 @@ where
      * ```
      */
     pub fn pop(&mut self) -> Option<T> {
-        if self.len == 0 {
+        let head = self.head.load(Ordering::Relaxed);
+        let tail = self.tail.load(Ordering::Relaxed);
+        if head == tail {
             return None;
         }
-        let item = self.buffer[self.tail];
-        self.tail = (self.tail + 1) % S;
-        self.len -= 1;
+        let idx = tail % S;
+        let item = self.buf()[idx];
+        self.tail.store(tail + 1, Ordering::Relaxed);
+        Some(item)
+    }
+
+    /**
+     * Pushes an item onto the front of the ring buffer, i.e. it will be the
+     * next item returned by `pop`. Decrements `tail` (wrapping from index `0`
+     * to `S - 1`) and stores the item at the new `tail`. Returns `Full` if
+     * the buffer has no free slots.
+     *
+     * Together with [`RingBuffer::pop_back`] this turns `RingBuffer` into a
+     * proper deque, which is handy for undo/pushback patterns such as
+     * returning an un-parsed byte to the front of a receive buffer.
+     * ```
+     * use ring_buffer_no_std::RingBuffer;
+     * let mut rb: RingBuffer<u32, 4096> = RingBuffer::new();
+     * rb.push(2).unwrap();
+     * rb.push_front(1).unwrap();
+     * assert_eq!(rb.pop(), Some(1));
+     * assert_eq!(rb.pop(), Some(2));
+     * ```
+     */
+    pub fn push_front(&mut self, item: T) -> Result<(), RingBufferError> {
+        let head = self.head.load(Ordering::Relaxed);
+        let tail = self.tail.load(Ordering::Relaxed);
+        if head.wrapping_sub(tail) == S {
+            return Err(RingBufferError::Full);
+        }
+        let new_tail = tail.wrapping_sub(1);
+        let idx = new_tail % S;
+        self.buf_mut()[idx] = item;
+        self.tail.store(new_tail, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /**
+     * Pops an item from the back of the ring buffer, i.e. the item most
+     * recently pushed by `push`. Decrements `head` (wrapping from index `0`
+     * to `S - 1`) and returns the item stored there. Returns `None` if the
+     * buffer is empty.
+     * ```
+     * use ring_buffer_no_std::RingBuffer;
+     * let mut rb: RingBuffer<u32, 4096> = RingBuffer::new();
+     * rb.push(1).unwrap();
+     * rb.push(2).unwrap();
+     * assert_eq!(rb.pop_back(), Some(2));
+     * assert_eq!(rb.pop_back(), Some(1));
+     * assert_eq!(rb.pop_back(), None);
+     * ```
+     */
+    pub fn pop_back(&mut self) -> Option<T> {
+        let head = self.head.load(Ordering::Relaxed);
+        let tail = self.tail.load(Ordering::Relaxed);
+        if head == tail {
+            return None;
+        }
+        let new_head = head.wrapping_sub(1);
+        let idx = new_head % S;
+        let item = self.buf()[idx];
+        self.head.store(new_head, Ordering::Relaxed);
         Some(item)
     }
 
@@ -104,9 +251,8 @@ where
      * ```
      */
     pub fn clear(&mut self) {
-        self.head = 0;
-        self.tail = 0;
-        self.len = 0;
+        self.head.store(Self::MID, Ordering::Relaxed);
+        self.tail.store(Self::MID, Ordering::Relaxed);
     }
 
     /**
@@ -122,11 +268,11 @@ where
      * ```
      */
     pub fn pop_continuous(&mut self, count: usize) -> Result<usize, RingBufferError> {
-        if count > self.len {
+        if count > self.len() {
             return Result::Err(RingBufferError::BeyondRange);
         }
-        self.tail = (self.tail + count) % S;
-        self.len -= count;
+        let tail = self.tail.load(Ordering::Relaxed);
+        self.tail.store(tail + count, Ordering::Relaxed);
 
         Ok(self.remaining_capacity())
     }
@@ -156,7 +302,7 @@ where
      */
     #[inline]
     pub fn remaining_capacity(&self) -> usize {
-        S - self.len
+        S - self.len()
     }
 
     /**
@@ -164,21 +310,21 @@ where
      */
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.len == 0
+        self.len() == 0
     }
     /**
      * Checks if the ring buffer is full.
      */
     #[inline]
     pub fn is_full(&self) -> bool {
-        self.len == S
+        self.len() == S
     }
     /**
      * Returns the current length of the ring buffer.
      */
     #[inline]
     pub fn len(&self) -> usize {
-        self.len
+        self.head.load(Ordering::Relaxed).wrapping_sub(self.tail.load(Ordering::Relaxed))
     }
 
     /**
@@ -188,7 +334,7 @@ where
      * use ring_buffer_no_std::RingBuffer;
      * let mut rb: RingBuffer<u32, 4096> = RingBuffer::new();
      * rb.write(&[1, 2, 3, 4]).unwrap();
-     * ``` 
+     * ```
      */
     pub fn write(&mut self, data: &[T]) -> Result<(), RingBufferError> {
         for &item in data {
@@ -223,7 +369,7 @@ where
         buffer.len()
     }
 
-    
+
     /**
      * Reads a slice of data from the ring buffer.
      * Returns an error if the requested length exceeds the current length of the buffer.
@@ -235,23 +381,646 @@ where
      * rb.push(2).unwrap();
      * let slice = rb.read_slice(2).unwrap();
      * assert_eq!(slice, &[1, 2]);
-     * ```  
+     * ```
      */
     pub fn read_slice(&self, len: usize) -> Result<&[T], RingBufferError> {
-        if len > self.len {
+        if len > self.len() {
             return Err(RingBufferError::BeyondRange);
         }
-        if self.tail < self.head {
-            // Data is contiguous
-            Ok(&self.buffer[self.tail..self.tail + len])
-        } else if self.tail + len <= S {
+        let tail_idx = self.tail.load(Ordering::Relaxed) % S;
+        if tail_idx + len <= S {
             // Data is contiguous from tail to end of buffer
-            Ok(&self.buffer[self.tail..self.tail + len])
+            Ok(&self.buf()[tail_idx..tail_idx + len])
         } else {
             // Data wraps around, cannot return as a single slice
             Err(RingBufferError::BufferIncomplete)
         }
     }
+
+    /**
+     * Returns a view of `len` elements starting `offset` items after `tail`,
+     * without advancing `tail` (or any other index). This lets a caller
+     * inspect buffered-but-unconsumed data repeatedly before finally popping
+     * it, e.g. re-reading unacknowledged bytes for a retransmission.
+     *
+     * Returns `BeyondRange` if `offset + len` exceeds the current length, and
+     * `BufferIncomplete` if the requested window wraps the physical end of
+     * the array, same as [`RingBuffer::read_slice`].
+     * ```
+     * use ring_buffer_no_std::RingBuffer;
+     * let mut rb: RingBuffer<u32, 4096> = RingBuffer::new();
+     * rb.write(&[1, 2, 3, 4]).unwrap();
+     * assert_eq!(rb.peek(1, 2).unwrap(), &[2, 3]);
+     * assert_eq!(rb.len(), 4); // peek does not consume anything
+     * ```
+     */
+    pub fn peek(&self, offset: usize, len: usize) -> Result<&[T], RingBufferError> {
+        if offset + len > self.len() {
+            return Err(RingBufferError::BeyondRange);
+        }
+        let start_idx = (self.tail.load(Ordering::Relaxed) + offset) % S;
+        if start_idx + len <= S {
+            Ok(&self.buf()[start_idx..start_idx + len])
+        } else {
+            Err(RingBufferError::BufferIncomplete)
+        }
+    }
+
+    /**
+     * Returns a mutable slice into the contiguous free region starting at `head`,
+     * clamped to at most `max` elements and to the physical end of the buffer array
+     * so the returned slice never overruns `tail` or wraps.
+     *
+     * The caller fills some prefix of the returned slice directly (e.g. from a DMA
+     * or network driver) and then calls [`RingBuffer::commit_write`] with the number
+     * of elements actually written. Because the free region can itself wrap around
+     * the end of the array, writing a full wrap's worth of data may require two
+     * calls to `enqueue_many`.
+     * ```
+     * use ring_buffer_no_std::RingBuffer;
+     * let mut rb: RingBuffer<u32, 4> = RingBuffer::new();
+     * let chunk = rb.enqueue_many(4);
+     * chunk[..2].copy_from_slice(&[1, 2]);
+     * rb.commit_write(2);
+     * assert_eq!(rb.len(), 2);
+     * ```
+     */
+    pub fn enqueue_many(&mut self, max: usize) -> &mut [T] {
+        let available = (S - self.len()).min(max);
+        let head_idx = self.head.load(Ordering::Relaxed) % S;
+        let contiguous = available.min(S - head_idx);
+        &mut self.buf_mut()[head_idx..head_idx + contiguous]
+    }
+
+    /**
+     * Advances `head` and `len` by `n` after the caller has written `n` elements
+     * into the slice returned by [`RingBuffer::enqueue_many`].
+     */
+    pub fn commit_write(&mut self, n: usize) {
+        let head = self.head.load(Ordering::Relaxed);
+        self.head.store(head + n, Ordering::Relaxed);
+    }
+
+    /**
+     * Returns an immutable slice into the contiguous allocated region starting at
+     * `tail`, clamped to at most `max` elements and to the physical end of the
+     * buffer array so the returned slice never passes `head` or wraps.
+     *
+     * The caller reads some prefix of the returned slice directly and then calls
+     * [`RingBuffer::commit_read`] with the number of elements actually consumed.
+     * Because the allocated region can itself wrap around the end of the array,
+     * reading a full wrap's worth of data may require two calls to `dequeue_many`.
+     * ```
+     * use ring_buffer_no_std::RingBuffer;
+     * let mut rb: RingBuffer<u32, 4> = RingBuffer::new();
+     * rb.write(&[1, 2, 3]).unwrap();
+     * let chunk = rb.dequeue_many(4);
+     * assert_eq!(chunk, &[1, 2, 3]);
+     * rb.commit_read(3);
+     * assert!(rb.is_empty());
+     * ```
+     */
+    pub fn dequeue_many(&self, max: usize) -> &[T] {
+        let available = self.len().min(max);
+        let tail_idx = self.tail.load(Ordering::Relaxed) % S;
+        let contiguous = available.min(S - tail_idx);
+        &self.buf()[tail_idx..tail_idx + contiguous]
+    }
+
+    /**
+     * Advances `tail` and decrements `len` by `n` after the caller has consumed `n`
+     * elements from the slice returned by [`RingBuffer::dequeue_many`].
+     */
+    pub fn commit_read(&mut self, n: usize) {
+        let tail = self.tail.load(Ordering::Relaxed);
+        self.tail.store(tail + n, Ordering::Relaxed);
+    }
+
+    /**
+     * Returns the live data as two contiguous slices: the segment from `tail`
+     * to the end of the array, then the segment from the start of the array
+     * up to `head`. The second slice is empty unless the data wraps the
+     * physical end of the array, so this always succeeds where
+     * [`RingBuffer::read_slice`] would return `BufferIncomplete`.
+     *
+     * This is the standard `VecDeque`-style view: callers can iterate or
+     * `copy_from_slice` both halves without an element-by-element loop.
+     * ```
+     * use ring_buffer_no_std::RingBuffer;
+     * let mut rb: RingBuffer<u32, 4> = RingBuffer::new();
+     * rb.write(&[1, 2, 3]).unwrap();
+     * rb.pop_continuous(2).unwrap();
+     * rb.write(&[4, 5]).unwrap();
+     * let (front, back) = rb.as_slices();
+     * assert_eq!(front, &[3, 4]);
+     * assert_eq!(back, &[5]);
+     * ```
+     */
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let len = self.len();
+        let tail_idx = self.tail.load(Ordering::Relaxed) % S;
+        let front_len = len.min(S - tail_idx);
+        let buf = self.buf();
+        (
+            &buf[tail_idx..tail_idx + front_len],
+            &buf[0..len - front_len],
+        )
+    }
+
+    /**
+     * Mutable variant of [`RingBuffer::as_slices`], for processing the live
+     * data in place without popping it.
+     * ```
+     * use ring_buffer_no_std::RingBuffer;
+     * let mut rb: RingBuffer<u32, 4> = RingBuffer::new();
+     * rb.write(&[1, 2, 3]).unwrap();
+     * rb.pop_continuous(2).unwrap();
+     * rb.write(&[4, 5]).unwrap();
+     * let (front, back) = rb.as_mut_slices();
+     * front[0] = 30;
+     * back[0] = 50;
+     * assert_eq!(rb.as_slices(), (&[30, 4][..], &[50][..]));
+     * ```
+     */
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let len = self.len();
+        let tail_idx = self.tail.load(Ordering::Relaxed) % S;
+        let front_len = len.min(S - tail_idx);
+        let (back_region, front_region) = self.buf_mut().split_at_mut(tail_idx);
+        let (front, _) = front_region.split_at_mut(front_len);
+        let back = &mut back_region[..len - front_len];
+        (front, back)
+    }
+
+    /**
+     * Returns an iterator over the live elements in FIFO order (oldest
+     * first), built on top of [`RingBuffer::as_slices`] so it steps across
+     * the wrap point without index math. Implements `ExactSizeIterator`, so
+     * `.len()` tracks the remaining count, and `DoubleEndedIterator`, so
+     * `.rev()` walks newest-first.
+     * ```
+     * use ring_buffer_no_std::RingBuffer;
+     * let mut rb: RingBuffer<u32, 4> = RingBuffer::new();
+     * rb.write(&[1, 2, 3]).unwrap();
+     * let mut it = rb.iter();
+     * assert_eq!(it.len(), 3);
+     * assert_eq!(it.next(), Some(&1));
+     * assert_eq!(rb.iter().next_back(), Some(&3));
+     * ```
+     */
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        Iter {
+            front: front.iter(),
+            back: back.iter(),
+        }
+    }
+
+    /**
+     * Returns an owning iterator that pops elements in FIFO order as it is
+     * driven, leaving the buffer empty once fully consumed (or partially
+     * drained if dropped early). `.rev()` pops newest-first via
+     * [`RingBuffer::pop_back`].
+     * ```
+     * use ring_buffer_no_std::RingBuffer;
+     * let mut rb: RingBuffer<u32, 4> = RingBuffer::new();
+     * rb.write(&[1, 2, 3]).unwrap();
+     * assert_eq!(rb.drain().next(), Some(1));
+     * assert_eq!(rb.len(), 2);
+     * ```
+     */
+    pub fn drain(&mut self) -> Drain<'_, T, S> {
+        Drain { rb: self }
+    }
+
+    /**
+     * Splits the ring buffer into a single-producer [`Producer`] handle and a
+     * single-consumer [`Consumer`] handle that can be driven from different
+     * execution contexts (e.g. an ISR producing while the main loop consumes)
+     * without a lock: the producer only ever writes `head`, the consumer only
+     * ever writes `tail`, and each reads the other's index with
+     * `Ordering::Acquire` before publishing its own with `Ordering::Release`.
+     *
+     * `S` must be a power of two: the handles index the backing array with
+     * `count & (S - 1)` instead of `count % S` to keep the hot path
+     * division-free. This is checked with a panic (not `debug_assert!`),
+     * since a silently wrong mask would alias indices and corrupt data
+     * rather than fail loudly.
+     *
+     * Takes `&'static mut self` rather than `&self`: the buffer must already
+     * live somewhere with `'static` lifetime (a `static`, or an equivalent
+     * leaked allocation) before it can be split. Taking the reference
+     * mutably means the borrow checker, not a doc comment, rejects a second
+     * `split()` on the same buffer and rejects any further use of the
+     * original binding (including read-only accessors like `iter()` or
+     * `len()`) for as long as the `Producer`/`Consumer` pair may still be
+     * alive — both would otherwise race on the same `head`/`tail`/`buffer`.
+     * ```
+     * use ring_buffer_no_std::RingBuffer;
+     * static mut RB: Option<RingBuffer<u32, 4>> = None;
+     * // SAFETY: accessed from a single thread, and only through this
+     * // exclusive `&'static mut` reference from here on.
+     * let rb: &'static mut RingBuffer<u32, 4> = unsafe {
+     *     RB = Some(RingBuffer::new());
+     *     (*core::ptr::addr_of_mut!(RB)).as_mut().unwrap()
+     * };
+     * let (mut producer, mut consumer) = rb.split();
+     * producer.push(1).unwrap();
+     * assert_eq!(consumer.pop(), Some(1));
+     * ```
+     */
+    pub fn split(&'static mut self) -> (Producer<'static, T, S>, Consumer<'static, T, S>) {
+        assert!(S.is_power_of_two(), "RingBuffer::split requires a power-of-two capacity");
+        let rb: &'static Self = self;
+        (Producer { rb }, Consumer { rb })
+    }
+}
+
+impl<T, const S: usize> Default for RingBuffer<T, S>
+where
+    T: Default + Copy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Borrowing, double-ended iterator over a [`RingBuffer`]'s live elements in
+/// FIFO order, returned by [`RingBuffer::iter`]. Just chains the two slices
+/// from [`RingBuffer::as_slices`]; `next()` drains `front` before touching
+/// `back` (and `next_back()` the reverse), and `len` is their combined
+/// remaining length rather than
+/// something tracked separately, so it can never drift out of sync.
+pub struct Iter<'a, T> {
+    front: core::slice::Iter<'a, T>,
+    back: core::slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+}
+
+/// Owning, double-ended iterator returned by [`RingBuffer::drain`]: each
+/// `next()`/`next_back()` call pops from the buffer it borrows, so the
+/// buffer is left empty once the iterator is fully consumed (or holding
+/// whatever remains if dropped early).
+pub struct Drain<'a, T, const S: usize>
+where
+    T: Default + Copy,
+{
+    rb: &'a mut RingBuffer<T, S>,
+}
+
+impl<'a, T, const S: usize> Iterator for Drain<'a, T, S>
+where
+    T: Default + Copy,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.rb.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const S: usize> DoubleEndedIterator for Drain<'a, T, S>
+where
+    T: Default + Copy,
+{
+    fn next_back(&mut self) -> Option<T> {
+        self.rb.pop_back()
+    }
+}
+
+impl<'a, T, const S: usize> ExactSizeIterator for Drain<'a, T, S>
+where
+    T: Default + Copy,
+{
+    fn len(&self) -> usize {
+        self.rb.len()
+    }
+}
+
+/// The single-producer half of a [`RingBuffer::split`] pair. Owns `head`;
+/// only reads `tail` (with `Ordering::Acquire`) to see how much room the
+/// consumer has freed up.
+pub struct Producer<'a, T, const S: usize>
+where
+    T: Default + Copy,
+{
+    rb: &'a RingBuffer<T, S>,
+}
+
+impl<'a, T, const S: usize> Producer<'a, T, S>
+where
+    T: Default + Copy,
+{
+    /// Pushes an item. Returns `RingBufferError::Full` if the consumer has
+    /// not yet freed a slot.
+    pub fn push(&mut self, item: T) -> Result<(), RingBufferError> {
+        let head = self.rb.head.load(Ordering::Relaxed);
+        let tail = self.rb.tail.load(Ordering::Acquire);
+        if head.wrapping_sub(tail) == S {
+            return Err(RingBufferError::Full);
+        }
+        let idx = head & (S - 1);
+        // SAFETY: `idx` is only ever written by the producer, and only after
+        // observing (via Acquire on `tail`) that the consumer has moved past it.
+        unsafe { (*self.rb.buffer.get())[idx] = item };
+        self.rb.head.store(head + 1, Ordering::Release);
+        Ok(())
+    }
+
+    /// Mutable slice into the contiguous free region starting at `head`, mirroring
+    /// [`RingBuffer::enqueue_many`]; pair with [`Producer::commit_write`].
+    pub fn enqueue_many(&mut self, max: usize) -> &mut [T] {
+        let head = self.rb.head.load(Ordering::Relaxed);
+        let tail = self.rb.tail.load(Ordering::Acquire);
+        let available = (S - head.wrapping_sub(tail)).min(max);
+        let head_idx = head & (S - 1);
+        let contiguous = available.min(S - head_idx);
+        // SAFETY: see `push`; the producer exclusively owns this region.
+        let buf = unsafe { &mut *self.rb.buffer.get() };
+        &mut buf[head_idx..head_idx + contiguous]
+    }
+
+    /// Publishes `n` elements written via [`Producer::enqueue_many`] to the consumer.
+    pub fn commit_write(&mut self, n: usize) {
+        let head = self.rb.head.load(Ordering::Relaxed);
+        self.rb.head.store(head + n, Ordering::Release);
+    }
+}
+
+/// The single-consumer half of a [`RingBuffer::split`] pair. Owns `tail`;
+/// only reads `head` (with `Ordering::Acquire`) to see what the producer has published.
+pub struct Consumer<'a, T, const S: usize>
+where
+    T: Default + Copy,
+{
+    rb: &'a RingBuffer<T, S>,
+}
+
+impl<'a, T, const S: usize> Consumer<'a, T, S>
+where
+    T: Default + Copy,
+{
+    /// Pops an item. Returns `None` if the producer has not yet published one.
+    pub fn pop(&mut self) -> Option<T> {
+        let tail = self.rb.tail.load(Ordering::Relaxed);
+        let head = self.rb.head.load(Ordering::Acquire);
+        if head == tail {
+            return None;
+        }
+        let idx = tail & (S - 1);
+        // SAFETY: `idx` was published by the producer (Acquire on `head` above)
+        // and is only ever read by the consumer.
+        let item = unsafe { (*self.rb.buffer.get())[idx] };
+        self.rb.tail.store(tail + 1, Ordering::Release);
+        Some(item)
+    }
+
+    /// Slice into the contiguous allocated region starting at `tail`, mirroring
+    /// [`RingBuffer::dequeue_many`]; pair with [`Consumer::commit_read`].
+    pub fn dequeue_many(&mut self, max: usize) -> &[T] {
+        let tail = self.rb.tail.load(Ordering::Relaxed);
+        let head = self.rb.head.load(Ordering::Acquire);
+        let available = head.wrapping_sub(tail).min(max);
+        let tail_idx = tail & (S - 1);
+        let contiguous = available.min(S - tail_idx);
+        // SAFETY: see `pop`; the consumer exclusively owns this region.
+        let buf = unsafe { &*self.rb.buffer.get() };
+        &buf[tail_idx..tail_idx + contiguous]
+    }
+
+    /// Releases `n` elements consumed via [`Consumer::dequeue_many`] back to the producer.
+    pub fn commit_read(&mut self, n: usize) {
+        let tail = self.rb.tail.load(Ordering::Relaxed);
+        self.rb.tail.store(tail + n, Ordering::Release);
+    }
+}
+
+/// Returned by [`Reassembler::add`] when placing a segment would need more
+/// than `N` contigs to describe the accepted window.
+#[derive(Debug)]
+pub struct TooManyHolesError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Contig {
+    hole_size: usize,
+    data_size: usize,
+}
+
+impl Contig {
+    const EMPTY: Contig = Contig { hole_size: 0, data_size: 0 };
+}
+
+/// Out-of-order reassembly layer that sits in front of a [`RingBuffer`] to
+/// support writing segments at arbitrary offsets and only releasing a
+/// contiguous prefix for consumption, e.g. TCP-style stream reassembly.
+///
+/// The accepted window is modeled as up to `N` [`Contig`] entries stored in a
+/// fixed array: `contigs[0..len]` alternates `hole, data, hole, data, ...`
+/// starting from the current read front (offset `0`). A `hole_size` of `0`
+/// on the first contig means data is available immediately at the front;
+/// see [`Reassembler::front_contiguous`].
+#[derive(Clone, Copy)]
+pub struct Reassembler<const N: usize> {
+    contigs: [Contig; N],
+    len: usize,
+}
+
+impl<const N: usize> Reassembler<N> {
+    /// Creates a `Reassembler` with nothing received yet.
+    pub fn new() -> Self {
+        Reassembler {
+            contigs: [Contig::EMPTY; N],
+            len: 0,
+        }
+    }
+
+    /// Bytes available at the front for consumption right now: the front
+    /// contig's `data_size` once its `hole_size` is zero, `0` otherwise.
+    pub fn front_contiguous(&self) -> usize {
+        if self.len > 0 && self.contigs[0].hole_size == 0 {
+            self.contigs[0].data_size
+        } else {
+            0
+        }
+    }
+
+    /// Drops `n` bytes already consumed from the front contig, e.g. after the
+    /// caller commits them into the underlying `RingBuffer`. `n` must not
+    /// exceed [`Reassembler::front_contiguous`].
+    pub fn advance(&mut self, n: usize) {
+        if n == 0 {
+            return;
+        }
+        debug_assert!(n <= self.front_contiguous());
+        self.contigs[0].data_size -= n;
+        if self.contigs[0].data_size == 0 {
+            self.remove_at(0);
+        }
+    }
+
+    /// Records a received run of `len` bytes starting `offset` bytes after
+    /// the current front, merging it with whatever is already tracked.
+    /// Returns the number of contiguous bytes now available at the front.
+    pub fn add(&mut self, offset: usize, len: usize) -> Result<usize, TooManyHolesError> {
+        if len == 0 {
+            return Ok(self.front_contiguous());
+        }
+        let mut scratch = *self;
+        scratch.add_inner(offset, len)?;
+        scratch.normalize();
+        *self = scratch;
+        Ok(self.front_contiguous())
+    }
+
+    fn add_inner(&mut self, mut offset: usize, mut remaining: usize) -> Result<(), TooManyHolesError> {
+        let mut i = 0;
+        while i < self.len {
+            let hole_size = self.contigs[i].hole_size;
+            if offset < hole_size {
+                break;
+            }
+            offset -= hole_size;
+            let data_size = self.contigs[i].data_size;
+            if offset < data_size {
+                let overlap = (data_size - offset).min(remaining);
+                remaining -= overlap;
+                if remaining == 0 {
+                    return Ok(());
+                }
+                offset = 0;
+            } else {
+                offset -= data_size;
+            }
+            i += 1;
+        }
+
+        if i == self.len {
+            // The segment starts beyond everything tracked so far.
+            return self.insert_at(i, Contig { hole_size: offset, data_size: remaining });
+        }
+
+        let hole_size = self.contigs[i].hole_size;
+        if offset + remaining < hole_size {
+            // Entirely inside the hole; does not reach the data that follows.
+            let trailing_hole = hole_size - offset - remaining;
+            let old_data = self.contigs[i].data_size;
+            self.contigs[i] = Contig { hole_size: offset, data_size: remaining };
+            return self.insert_at(i + 1, Contig { hole_size: trailing_hole, data_size: old_data });
+        }
+
+        // Reaches (or passes) the data run that follows: shrink the hole to
+        // its unfilled leading part and grow the data run to cover
+        // everything now known to be present, merging forward through any
+        // further contigs the new data reaches.
+        let consumed_from_hole = hole_size - offset;
+        self.contigs[i].hole_size = offset;
+        let old_data = self.contigs[i].data_size;
+        let mut filled_past_hole = remaining - consumed_from_hole;
+        if filled_past_hole <= old_data {
+            self.contigs[i].data_size = consumed_from_hole + old_data;
+            return Ok(());
+        }
+        filled_past_hole -= old_data;
+        let mut merged_data = consumed_from_hole + old_data;
+        loop {
+            if i + 1 >= self.len {
+                merged_data += filled_past_hole;
+                break;
+            }
+            let next_hole = self.contigs[i + 1].hole_size;
+            if filled_past_hole < next_hole {
+                merged_data += filled_past_hole;
+                self.contigs[i + 1].hole_size -= filled_past_hole;
+                break;
+            }
+            let next_data = self.contigs[i + 1].data_size;
+            merged_data += next_hole + next_data;
+            self.remove_at(i + 1);
+            if filled_past_hole <= next_hole + next_data {
+                // The new run lands inside (or exactly at the end of) this
+                // contig's data; everything through the end of that data is
+                // now contiguous regardless of how far the run actually
+                // reaches, so there is nothing left to merge further.
+                break;
+            }
+            filled_past_hole -= next_hole + next_data;
+        }
+        self.contigs[i].data_size = merged_data;
+        Ok(())
+    }
+
+    /// Merges any contig whose `hole_size` is zero into its predecessor; this
+    /// can happen after `add_inner` grows a data run up to, or past, the
+    /// start of the next one.
+    fn normalize(&mut self) {
+        let mut k = 1;
+        while k < self.len {
+            if self.contigs[k].hole_size == 0 {
+                let merged = self.contigs[k].data_size;
+                self.contigs[k - 1].data_size += merged;
+                self.remove_at(k);
+            } else {
+                k += 1;
+            }
+        }
+    }
+
+    fn insert_at(&mut self, idx: usize, contig: Contig) -> Result<(), TooManyHolesError> {
+        if self.len == N {
+            return Err(TooManyHolesError);
+        }
+        let mut j = self.len;
+        while j > idx {
+            self.contigs[j] = self.contigs[j - 1];
+            j -= 1;
+        }
+        self.contigs[idx] = contig;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn remove_at(&mut self, idx: usize) {
+        for j in idx..self.len - 1 {
+            self.contigs[j] = self.contigs[j + 1];
+        }
+        self.len -= 1;
+        self.contigs[self.len] = Contig::EMPTY;
+    }
+}
+
+impl<const N: usize> Default for Reassembler<N> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg(test)]
@@ -308,20 +1077,311 @@ mod tests {
 
         assert!(rb.is_empty());
 
-        assert_eq!(size_of::<RingBuffer<u8, 1>>(), 32);
-        assert_eq!(size_of::<RingBuffer<u8, 2>>(), 32);
-        assert_eq!(size_of::<RingBuffer<u8, 4>>(), 32);
-        assert_eq!(size_of::<RingBuffer<u8, 8>>(), 32);
-        assert_eq!(size_of::<RingBuffer<u8, 16>>(), 40);
-        assert_eq!(size_of::<RingBuffer<u8, 32>>(), 56);
-        assert_eq!(size_of::<RingBuffer<u8, 64>>(), 88);
-        assert_eq!(size_of::<RingBuffer<u8, 128>>(), 152);
-        assert_eq!(size_of::<RingBuffer<u8, 256>>(), 280);
-        assert_eq!(size_of::<RingBuffer<u8, 512>>(), 536);
-        assert_eq!(size_of::<RingBuffer<u8, 1024>>(), 1048);
-        assert_eq!(size_of::<RingBuffer<u8, 4096>>(), 4120);
-        assert_eq!(size_of::<RingBuffer<u8, 10>>(), 40); // 10 * 4 bytes for u8 + 3 * 4 bytes for usize
-    }
-
-    
+        assert_eq!(size_of::<RingBuffer<u8, 1>>(), 24);
+        assert_eq!(size_of::<RingBuffer<u8, 2>>(), 24);
+        assert_eq!(size_of::<RingBuffer<u8, 4>>(), 24);
+        assert_eq!(size_of::<RingBuffer<u8, 8>>(), 24);
+        assert_eq!(size_of::<RingBuffer<u8, 16>>(), 32);
+        assert_eq!(size_of::<RingBuffer<u8, 32>>(), 48);
+        assert_eq!(size_of::<RingBuffer<u8, 64>>(), 80);
+        assert_eq!(size_of::<RingBuffer<u8, 128>>(), 144);
+        assert_eq!(size_of::<RingBuffer<u8, 256>>(), 272);
+        assert_eq!(size_of::<RingBuffer<u8, 512>>(), 528);
+        assert_eq!(size_of::<RingBuffer<u8, 1024>>(), 1040);
+        assert_eq!(size_of::<RingBuffer<u8, 4096>>(), 4112);
+        assert_eq!(size_of::<RingBuffer<u8, 10>>(), 32); // 10 * 1 byte for u8 + 2 * 8 bytes for the atomic indices, rounded up
+    }
+
+    #[test]
+    fn enqueue_dequeue_many_wrap_boundary() {
+        let mut rb: RingBuffer<u32, 4> = RingBuffer::new();
+
+        // Fill the buffer directly through the zero-copy accessor.
+        let chunk = rb.enqueue_many(4);
+        assert_eq!(chunk.len(), 4);
+        chunk.copy_from_slice(&[1, 2, 3, 4]);
+        rb.commit_write(4);
+        assert!(rb.is_full());
+
+        // Free up space at the front so head wraps around on the next write.
+        let chunk = rb.dequeue_many(2);
+        assert_eq!(chunk, &[1, 2]);
+        rb.commit_read(2);
+        assert_eq!(rb.len(), 2);
+
+        // head is now at index 0 (wrapped); only 2 slots are free and contiguous.
+        let chunk = rb.enqueue_many(4);
+        assert_eq!(chunk.len(), 2);
+        chunk.copy_from_slice(&[5, 6]);
+        rb.commit_write(2);
+        assert!(rb.is_full());
+
+        // tail is now at index 2; the allocated region is split by the array end,
+        // so a single call only exposes the elements up to the physical end.
+        let chunk = rb.dequeue_many(4);
+        assert_eq!(chunk, &[3, 4]);
+        rb.commit_read(2);
+
+        let chunk = rb.dequeue_many(4);
+        assert_eq!(chunk, &[5, 6]);
+        rb.commit_read(2);
+        assert!(rb.is_empty());
+    }
+
+    #[test]
+    fn push_overwrite_retains_last_s_items() {
+        let mut rb: RingBuffer<u32, 3> = RingBuffer::new();
+
+        assert_eq!(rb.push_overwrite(1), None);
+        assert_eq!(rb.push_overwrite(2), None);
+        assert_eq!(rb.push_overwrite(3), None);
+        assert!(rb.is_full());
+
+        // Buffer is full: each further push evicts the oldest retained item.
+        assert_eq!(rb.push_overwrite(4), Some(1));
+        assert_eq!(rb.push_overwrite(5), Some(2));
+        assert_eq!(rb.push_overwrite(6), Some(3));
+
+        assert_eq!(rb.len(), 3);
+        let mut buffer = [0; 3];
+        rb.read(&mut buffer);
+        assert_eq!(buffer, [4, 5, 6]);
+    }
+
+    #[test]
+    fn peek_does_not_consume_and_rejects_out_of_range_or_wrapped_windows() {
+        let mut rb: RingBuffer<u32, 4> = RingBuffer::new();
+        rb.write(&[1, 2, 3, 4]).unwrap();
+
+        assert_eq!(rb.peek(0, 2).unwrap(), &[1, 2]);
+        assert_eq!(rb.peek(2, 2).unwrap(), &[3, 4]);
+        assert_eq!(rb.len(), 4); // nothing was consumed
+
+        assert!(matches!(rb.peek(3, 2), Err(RingBufferError::BeyondRange)));
+
+        rb.pop_continuous(2).unwrap();
+        rb.write(&[5, 6]).unwrap();
+        // tail is now at index 2, so a window at offset 1 covers index 3 then index 0 and wraps.
+        assert!(matches!(rb.peek(1, 2), Err(RingBufferError::BufferIncomplete)));
+    }
+
+    #[test]
+    fn push_front_and_pop_back_form_a_deque() {
+        let mut rb: RingBuffer<u32, 3> = RingBuffer::new();
+
+        rb.push(2).unwrap();
+        rb.push(3).unwrap();
+        rb.push_front(1).unwrap();
+        assert_eq!(rb.len(), 3);
+        assert!(rb.is_full());
+        assert!(matches!(rb.push_front(0), Err(RingBufferError::Full)));
+
+        assert_eq!(rb.pop_back(), Some(3));
+        assert_eq!(rb.pop(), Some(1));
+        assert_eq!(rb.pop(), Some(2));
+        assert_eq!(rb.pop_back(), None);
+    }
+
+    #[test]
+    fn as_slices_covers_empty_non_wrapped_wrapped_and_full() {
+        let mut rb: RingBuffer<u32, 4> = RingBuffer::new();
+
+        // Empty.
+        assert_eq!(rb.as_slices(), (&[][..], &[][..]));
+
+        // Non-wrapped: all the live data sits before the physical end.
+        rb.write(&[1, 2]).unwrap();
+        assert_eq!(rb.as_slices(), (&[1, 2][..], &[][..]));
+
+        // Full, still non-wrapped.
+        rb.write(&[3, 4]).unwrap();
+        assert_eq!(rb.as_slices(), (&[1, 2, 3, 4][..], &[][..]));
+
+        // Consume the front and refill so the data wraps the physical end.
+        rb.pop_continuous(2).unwrap();
+        rb.write(&[5, 6]).unwrap();
+        assert_eq!(rb.as_slices(), (&[3, 4][..], &[5, 6][..]));
+
+        let (front, back) = rb.as_mut_slices();
+        front[0] = 30;
+        back[0] = 50;
+        assert_eq!(rb.as_slices(), (&[30, 4][..], &[50, 6][..]));
+    }
+
+    #[test]
+    fn iter_walks_fifo_across_the_wrap_point_forwards_and_backwards() {
+        let mut rb: RingBuffer<u32, 4> = RingBuffer::new();
+        rb.write(&[1, 2, 3, 4]).unwrap();
+        rb.pop_continuous(2).unwrap();
+        rb.write(&[5, 6]).unwrap();
+        // Live data is now [3, 4, 5, 6], wrapping the physical end.
+
+        let mut it = rb.iter();
+        assert_eq!(it.len(), 4);
+        assert_eq!(it.next(), Some(&3));
+        assert_eq!(it.len(), 3);
+        assert_eq!(it.next_back(), Some(&6));
+        assert_eq!(it.len(), 2);
+        assert_eq!(it.next(), Some(&4));
+        assert_eq!(it.next_back(), Some(&5));
+        assert_eq!(it.len(), 0);
+        assert_eq!(it.next(), None);
+
+        assert_eq!(rb.iter().count(), 4);
+        assert_eq!(rb.iter().next_back(), Some(&6));
+    }
+
+    #[test]
+    fn drain_pops_as_it_yields_front_and_back() {
+        let mut rb: RingBuffer<u32, 4> = RingBuffer::new();
+        rb.write(&[1, 2, 3, 4]).unwrap();
+
+        let mut drain = rb.drain();
+        assert_eq!(drain.len(), 4);
+        assert_eq!(drain.next(), Some(1));
+        assert_eq!(drain.next_back(), Some(4));
+        assert_eq!(drain.next(), Some(2));
+        assert_eq!(drain.next_back(), Some(3));
+        assert_eq!(drain.next(), None);
+
+        assert!(rb.is_empty());
+    }
+
+    #[test]
+    fn split_produces_and_consumes_across_handles() {
+        static mut RB: Option<RingBuffer<u32, 4>> = None;
+        // SAFETY: this test does not touch `RB` anywhere else.
+        let rb: &'static mut RingBuffer<u32, 4> = unsafe {
+            RB = Some(RingBuffer::new());
+            (*core::ptr::addr_of_mut!(RB)).as_mut().unwrap()
+        };
+        let (mut producer, mut consumer) = rb.split();
+
+        assert_eq!(consumer.pop(), None);
+
+        producer.push(1).unwrap();
+        producer.push(2).unwrap();
+        producer.push(3).unwrap();
+        producer.push(4).unwrap();
+        assert!(producer.push(5).is_err());
+
+        assert_eq!(consumer.pop(), Some(1));
+        assert_eq!(consumer.pop(), Some(2));
+        producer.push(5).unwrap();
+        producer.push(6).unwrap();
+        assert_eq!(consumer.pop(), Some(3));
+        assert_eq!(consumer.pop(), Some(4));
+        assert_eq!(consumer.pop(), Some(5));
+        assert_eq!(consumer.pop(), Some(6));
+        assert_eq!(consumer.pop(), None);
+    }
+
+    #[test]
+    fn split_many_accessors_wrap_correctly() {
+        static mut RB: Option<RingBuffer<u32, 4>> = None;
+        // SAFETY: this test does not touch `RB` anywhere else.
+        let rb: &'static mut RingBuffer<u32, 4> = unsafe {
+            RB = Some(RingBuffer::new());
+            (*core::ptr::addr_of_mut!(RB)).as_mut().unwrap()
+        };
+        let (mut producer, mut consumer) = rb.split();
+
+        let chunk = producer.enqueue_many(4);
+        chunk.copy_from_slice(&[1, 2, 3, 4]);
+        producer.commit_write(4);
+
+        let chunk = consumer.dequeue_many(2);
+        assert_eq!(chunk, &[1, 2]);
+        consumer.commit_read(2);
+
+        let chunk = producer.enqueue_many(4);
+        chunk.copy_from_slice(&[5, 6]);
+        producer.commit_write(2);
+
+        let chunk = consumer.dequeue_many(4);
+        assert_eq!(chunk, &[3, 4]);
+        consumer.commit_read(2);
+        let chunk = consumer.dequeue_many(4);
+        assert_eq!(chunk, &[5, 6]);
+        consumer.commit_read(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "power-of-two")]
+    fn split_rejects_non_power_of_two_capacity() {
+        static mut RB: Option<RingBuffer<u32, 3>> = None;
+        // SAFETY: this test does not touch `RB` anywhere else.
+        let rb: &'static mut RingBuffer<u32, 3> = unsafe {
+            RB = Some(RingBuffer::new());
+            (*core::ptr::addr_of_mut!(RB)).as_mut().unwrap()
+        };
+        let _ = rb.split();
+    }
+
+    #[test]
+    fn reassembler_in_order_fills() {
+        let mut reassembler: Reassembler<4> = Reassembler::new();
+        assert_eq!(reassembler.front_contiguous(), 0);
+
+        assert_eq!(reassembler.add(0, 3).unwrap(), 3);
+        assert_eq!(reassembler.add(3, 2).unwrap(), 5);
+
+        reassembler.advance(5);
+        assert_eq!(reassembler.front_contiguous(), 0);
+    }
+
+    #[test]
+    fn reassembler_hole_filled_later_then_earlier() {
+        let mut reassembler: Reassembler<4> = Reassembler::new();
+
+        // A segment arrives after a gap: front stays empty, a hole is tracked.
+        assert_eq!(reassembler.add(10, 5).unwrap(), 0);
+
+        // Filling the part of the hole touching the data leaves a smaller
+        // hole before the (now larger) data run.
+        assert_eq!(reassembler.add(6, 4).unwrap(), 0);
+
+        // Filling the rest of the hole exposes everything received so far,
+        // merged into one contiguous run.
+        assert_eq!(reassembler.add(0, 6).unwrap(), 15);
+    }
+
+    #[test]
+    fn reassembler_overlapping_segments_merge() {
+        let mut reassembler: Reassembler<4> = Reassembler::new();
+
+        assert_eq!(reassembler.add(0, 4).unwrap(), 4);
+        // Overlaps the tail of the first segment and extends past it.
+        assert_eq!(reassembler.add(2, 4).unwrap(), 6);
+        // Entirely inside what is already known: a no-op.
+        assert_eq!(reassembler.add(1, 2).unwrap(), 6);
+    }
+
+    #[test]
+    fn reassembler_too_many_holes() {
+        let mut reassembler: Reassembler<2> = Reassembler::new();
+
+        // Two disjoint segments with gaps before, between, and after use up
+        // both available contigs.
+        assert_eq!(reassembler.add(10, 1).unwrap(), 0);
+        assert_eq!(reassembler.add(20, 1).unwrap(), 0);
+
+        // A third disjoint segment would need a third contig.
+        assert!(matches!(reassembler.add(30, 1), Err(TooManyHolesError)));
+    }
+
+    #[test]
+    fn reassembler_merge_forward_lands_inside_next_contig_data() {
+        let mut reassembler: Reassembler<8> = Reassembler::new();
+
+        assert_eq!(reassembler.add(0, 2).unwrap(), 2);
+        // Disjoint segments further out don't change what's at the front.
+        assert_eq!(reassembler.add(5, 2).unwrap(), 2);
+        assert_eq!(reassembler.add(9, 5).unwrap(), 2);
+
+        // Fills the hole before the second contig, fully absorbs its
+        // hole+data, and then lands inside (not past) the third contig's
+        // data run rather than exactly at its end.
+        assert_eq!(reassembler.add(2, 10).unwrap(), 14);
+    }
 }